@@ -1,3 +1,4 @@
+use fpush_retry::{RetryConfig, TimeoutConfig};
 use serde::{Deserialize, Serialize};
 
 /// Configuration for Acrobits singlepush API
@@ -7,11 +8,21 @@ pub struct AcrobitsConfig {
     endpoint: String,
     /// Application ID for Acrobits
     app_id: String,
+    /// Request timeout and retry/backoff policy for this module
+    #[serde(default)]
+    timeout: TimeoutConfig,
+    #[serde(default)]
+    retry: RetryConfig,
 }
 
 impl AcrobitsConfig {
     pub fn new(endpoint: String, app_id: String) -> Self {
-        Self { endpoint, app_id }
+        Self {
+            endpoint,
+            app_id,
+            timeout: TimeoutConfig::default(),
+            retry: RetryConfig::default(),
+        }
     }
 
     pub fn endpoint(&self) -> &str {
@@ -21,6 +32,14 @@ impl AcrobitsConfig {
     pub fn app_id(&self) -> &str {
         &self.app_id
     }
+
+    pub fn timeout(&self) -> &TimeoutConfig {
+        &self.timeout
+    }
+
+    pub fn retry(&self) -> &RetryConfig {
+        &self.retry
+    }
 }
 
 impl Default for AcrobitsConfig {
@@ -28,6 +47,8 @@ impl Default for AcrobitsConfig {
         Self {
             endpoint: "https://pnm.cloudsoftphone.com/pnm2/send".to_string(),
             app_id: String::new(),
+            timeout: TimeoutConfig::default(),
+            retry: RetryConfig::default(),
         }
     }
 }
@@ -1,4 +1,4 @@
-use fpush_traits::push::{PushError, PushResult, PushTrait};
+use fpush_traits::push::{PushError, PushKind, PushMetadata, PushResult, PushTrait};
 use async_trait::async_trait;
 use log::{debug, error};
 use serde::{Deserialize, Serialize};
@@ -37,8 +37,14 @@ impl FpushAcrobits {
             return Err(PushError::CertLoading);
         }
 
-        let client = reqwest::Client::new();
-        
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout().request_timeout())
+            .build()
+            .map_err(|e| {
+                error!("Failed to build Acrobits HTTP client: {}", e);
+                PushError::CertLoading
+            })?;
+
         Ok(Self {
             client,
             config: config.clone(),
@@ -46,15 +52,56 @@ impl FpushAcrobits {
     }
 }
 
+/// Map a notification kind/metadata pair to the Acrobits verb and message
+/// body that best represents it on the device.
+fn acrobits_verb_and_message(kind: &PushKind, metadata: &PushMetadata) -> (&'static str, String) {
+    match kind {
+        PushKind::GenericMessage => ("NotifyGenericTextMessage", "New Message".to_string()),
+        PushKind::IncomingCall => (
+            "NotifyIncomingCall",
+            metadata
+                .caller_id
+                .clone()
+                .unwrap_or_else(|| "Incoming call".to_string()),
+        ),
+        PushKind::MissedCall => (
+            "NotifyMissedCall",
+            metadata
+                .caller_id
+                .clone()
+                .unwrap_or_else(|| "Missed call".to_string()),
+        ),
+        PushKind::Voicemail => (
+            "NotifyMWI",
+            format!("{} new voicemail message(s)", metadata.unread_count.unwrap_or(1)),
+        ),
+    }
+}
+
 #[async_trait]
 impl PushTrait for FpushAcrobits {
-    async fn send(&self, token: String) -> PushResult<()> {
+    async fn send(&self, token: String, payload: Vec<u8>) -> PushResult<()> {
+        self.send_with(token, payload, PushKind::default(), PushMetadata::default())
+            .await
+    }
+
+    // Acrobits' wire format has fixed verb/message fields and no slot for
+    // arbitrary content, so the payload is accepted but not forwarded.
+    async fn send_with(
+        &self,
+        token: String,
+        _payload: Vec<u8>,
+        kind: PushKind,
+        metadata: PushMetadata,
+    ) -> PushResult<()> {
+        let (verb, message) = acrobits_verb_and_message(&kind, &metadata);
+
         // Build the push request
         let request = AcrobitsRequest {
-            verb: "NotifyGenericTextMessage".to_string(),
+            verb: verb.to_string(),
             app_id: self.config.app_id().to_string(),
             device_token: token.clone(),
-            message: "New Message".to_string(),
+            message,
         };
 
         debug!(
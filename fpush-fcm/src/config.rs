@@ -0,0 +1,46 @@
+use fpush_retry::{RetryConfig, TimeoutConfig};
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the FCM HTTP v1 push backend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FcmConfig {
+    /// Path to the Google service-account JSON key file used to mint access tokens
+    service_account_file: String,
+    /// Request timeout and retry/backoff policy for this module
+    #[serde(default)]
+    timeout: TimeoutConfig,
+    #[serde(default)]
+    retry: RetryConfig,
+}
+
+impl FcmConfig {
+    pub fn new(service_account_file: String) -> Self {
+        Self {
+            service_account_file,
+            timeout: TimeoutConfig::default(),
+            retry: RetryConfig::default(),
+        }
+    }
+
+    pub fn service_account_file(&self) -> &str {
+        &self.service_account_file
+    }
+
+    pub fn timeout(&self) -> &TimeoutConfig {
+        &self.timeout
+    }
+
+    pub fn retry(&self) -> &RetryConfig {
+        &self.retry
+    }
+}
+
+impl Default for FcmConfig {
+    fn default() -> Self {
+        Self {
+            service_account_file: String::new(),
+            timeout: TimeoutConfig::default(),
+            retry: RetryConfig::default(),
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod config;
+mod token;
+pub mod push;
+
+pub use config::FcmConfig;
+pub use push::FpushFcm;
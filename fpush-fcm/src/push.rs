@@ -0,0 +1,123 @@
+use fpush_traits::push::{PushError, PushResult, PushTrait};
+use async_trait::async_trait;
+use log::{debug, error, warn};
+use serde::Serialize;
+use std::collections::HashMap;
+use crate::config::FcmConfig;
+use crate::token::FcmTokenSource;
+
+pub struct FpushFcm {
+    client: reqwest::Client,
+    tokens: FcmTokenSource,
+}
+
+/// Request payload for the FCM HTTP v1 send endpoint
+#[derive(Debug, Serialize)]
+struct FcmRequest {
+    message: FcmMessage,
+}
+
+#[derive(Debug, Serialize)]
+struct FcmMessage {
+    token: String,
+    data: HashMap<String, String>,
+}
+
+impl FpushFcm {
+    pub fn init(config: &FcmConfig) -> PushResult<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout().request_timeout())
+            .build()
+            .map_err(|e| {
+                error!("Failed to build FCM HTTP client: {}", e);
+                PushError::CertLoading
+            })?;
+        let tokens = FcmTokenSource::load(client.clone(), config)?;
+
+        Ok(Self { client, tokens })
+    }
+
+    fn endpoint(&self) -> String {
+        format!(
+            "https://fcm.googleapis.com/v1/projects/{}/messages:send",
+            self.tokens.project_id()
+        )
+    }
+
+    async fn send_with_token(
+        &self,
+        token: &str,
+        access_token: &str,
+        payload: &[u8],
+        allow_retry: bool,
+    ) -> PushResult<()> {
+        let mut data = HashMap::new();
+        if !payload.is_empty() {
+            data.insert("payload".to_string(), String::from_utf8_lossy(payload).into_owned());
+        }
+
+        let request = FcmRequest {
+            message: FcmMessage {
+                token: token.to_string(),
+                data,
+            },
+        };
+
+        debug!("Sending FCM push to token: {}", token);
+
+        let response = match self
+            .client
+            .post(self.endpoint())
+            .bearer_auth(access_token)
+            .json(&request)
+            .send()
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                error!("Failed to send request to FCM: {}", e);
+                return Err(PushError::PushEndpointTmp);
+            }
+        };
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+
+        match status.as_u16() {
+            // 401 means the access token was rejected; refresh it and retry once
+            401 if allow_retry => {
+                warn!("FCM access token rejected, refreshing and retrying");
+                self.tokens.invalidate().await;
+                let access_token = self.tokens.token().await?;
+                Box::pin(self.send_with_token(token, &access_token, payload, false)).await
+            }
+            401 => Err(PushError::PushEndpointPersistent),
+            // 404 with reason UNREGISTERED means the device token is no longer valid
+            404 => Err(PushError::TokenBlocked),
+            429 => Err(PushError::PushEndpointTmp),
+            code if status.is_server_error() => {
+                debug!("FCM returned server error {}", code);
+                Err(PushError::PushEndpointTmp)
+            }
+            code => {
+                let body = response.text().await.unwrap_or_default();
+                if body.contains("UNREGISTERED") {
+                    Err(PushError::TokenBlocked)
+                } else {
+                    error!("Received unhandled status code from FCM: {}", code);
+                    Err(PushError::Unknown(code))
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl PushTrait for FpushFcm {
+    async fn send(&self, token: String, payload: Vec<u8>) -> PushResult<()> {
+        let access_token = self.tokens.token().await?;
+        self.send_with_token(&token, &access_token, &payload, true).await
+    }
+}
@@ -0,0 +1,139 @@
+use fpush_traits::push::{PushError, PushResult};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use log::error;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::RwLock;
+use crate::config::FcmConfig;
+
+/// OAuth2 scope requested for the FCM HTTP v1 API
+const FCM_SCOPE: &str = "https://www.googleapis.com/auth/firebase.messaging";
+/// Lifetime of the self-signed JWT assertion, per Google's token exchange requirements
+const ASSERTION_TTL_SECS: i64 = 3600;
+/// Cache the exchanged access token for less than its real lifetime so it is
+/// always refreshed before the server would reject it
+const TOKEN_CACHE_TTL_SECS: u64 = 55 * 60;
+
+/// Fields of a Google service-account JSON key file that are relevant to us
+#[derive(Debug, Clone, Deserialize)]
+struct ServiceAccountKey {
+    client_email: String,
+    private_key: String,
+    token_uri: String,
+    project_id: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Claims {
+    iss: String,
+    scope: String,
+    aud: String,
+    iat: i64,
+    exp: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+}
+
+/// Mints and caches short-lived OAuth2 access tokens for the FCM HTTP v1 API
+/// from a Google service-account key, mirroring the short-lived-token pattern
+/// used by mature FCM clients.
+pub struct FcmTokenSource {
+    client: reqwest::Client,
+    key: ServiceAccountKey,
+    cached: Arc<RwLock<Option<(String, Instant)>>>,
+}
+
+impl FcmTokenSource {
+    pub fn load(client: reqwest::Client, config: &FcmConfig) -> PushResult<Self> {
+        let raw = std::fs::read_to_string(config.service_account_file()).map_err(|e| {
+            error!("Failed to read FCM service account file: {}", e);
+            PushError::CertLoading
+        })?;
+        let key: ServiceAccountKey = serde_json::from_str(&raw).map_err(|e| {
+            error!("Failed to parse FCM service account file: {}", e);
+            PushError::CertLoading
+        })?;
+
+        Ok(Self {
+            client,
+            key,
+            cached: Arc::new(RwLock::new(None)),
+        })
+    }
+
+    pub fn project_id(&self) -> &str {
+        &self.key.project_id
+    }
+
+    pub async fn token(&self) -> PushResult<String> {
+        if let Some((token, expires_at)) = self.cached.read().await.clone() {
+            if Instant::now() < expires_at {
+                return Ok(token);
+            }
+        }
+        self.refresh().await
+    }
+
+    pub async fn invalidate(&self) {
+        *self.cached.write().await = None;
+    }
+
+    async fn refresh(&self) -> PushResult<String> {
+        let assertion = self.sign_assertion()?;
+
+        let params = [
+            ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+            ("assertion", assertion.as_str()),
+        ];
+
+        let response = self
+            .client
+            .post(&self.key.token_uri)
+            .form(&params)
+            .send()
+            .await
+            .map_err(|e| {
+                error!("Failed to exchange FCM JWT for an access token: {}", e);
+                PushError::PushEndpointTmp
+            })?;
+
+        let token_response: TokenResponse = response.json().await.map_err(|e| {
+            error!("Failed to parse FCM token response: {}", e);
+            PushError::PushEndpointTmp
+        })?;
+
+        let expires_at = Instant::now() + Duration::from_secs(TOKEN_CACHE_TTL_SECS);
+        *self.cached.write().await = Some((token_response.access_token.clone(), expires_at));
+
+        Ok(token_response.access_token)
+    }
+
+    fn sign_assertion(&self) -> PushResult<String> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+
+        let claims = Claims {
+            iss: self.key.client_email.clone(),
+            scope: FCM_SCOPE.to_string(),
+            aud: self.key.token_uri.clone(),
+            iat: now,
+            exp: now + ASSERTION_TTL_SECS,
+        };
+
+        let encoding_key = EncodingKey::from_rsa_pem(self.key.private_key.as_bytes()).map_err(|e| {
+            error!("Failed to load FCM service account private key: {}", e);
+            PushError::CertLoading
+        })?;
+
+        encode(&Header::new(Algorithm::RS256), &claims, &encoding_key).map_err(|e| {
+            error!("Failed to sign FCM JWT assertion: {}", e);
+            PushError::CertLoading
+        })
+    }
+}
@@ -0,0 +1,18 @@
+use fpush_acrobits::AcrobitsConfig;
+use fpush_fcm::FcmConfig;
+use fpush_wns::WnsConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Which backend a named push module uses, together with its configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum PushModuleConfig {
+    Acrobits(AcrobitsConfig),
+    Wns(WnsConfig),
+    Fcm(FcmConfig),
+}
+
+/// The `push_modules` section of settings.json: one entry per named module,
+/// e.g. `{"ios": {"type": "acrobits", ...}, "android": {"type": "fcm", ...}}`
+pub type PushModulesConfig = HashMap<String, PushModuleConfig>;
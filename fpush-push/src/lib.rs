@@ -0,0 +1,86 @@
+pub mod config;
+
+use fpush_acrobits::FpushAcrobits;
+use fpush_fcm::FpushFcm;
+use fpush_retry::{retry_with_backoff, RetryConfig};
+use fpush_traits::push::{PushError, PushKind, PushMetadata, PushResult, PushTrait};
+use fpush_wns::FpushWns;
+use log::error;
+use std::collections::HashMap;
+
+use crate::config::{PushModuleConfig, PushModulesConfig};
+
+struct PushModule {
+    backend: Box<dyn PushTrait + Send + Sync>,
+    retry: RetryConfig,
+}
+
+/// Dispatches push requests to the configured backend for each named module,
+/// retrying transient failures with backoff before surfacing an error.
+pub struct FpushPush {
+    modules: HashMap<String, PushModule>,
+}
+
+impl FpushPush {
+    pub async fn new(modules: &PushModulesConfig) -> Self {
+        let mut built = HashMap::with_capacity(modules.len());
+
+        for (name, module_config) in modules {
+            let module = match module_config {
+                PushModuleConfig::Acrobits(config) => FpushAcrobits::init(config).map(|backend| PushModule {
+                    backend: Box::new(backend),
+                    retry: config.retry().clone(),
+                }),
+                PushModuleConfig::Wns(config) => FpushWns::init(config).map(|backend| PushModule {
+                    backend: Box::new(backend),
+                    retry: config.retry().clone(),
+                }),
+                PushModuleConfig::Fcm(config) => FpushFcm::init(config).map(|backend| PushModule {
+                    backend: Box::new(backend),
+                    retry: config.retry().clone(),
+                }),
+            };
+
+            match module {
+                Ok(module) => {
+                    built.insert(name.clone(), module);
+                }
+                Err(e) => {
+                    error!("Failed to initialize push module '{}': {:?}", name, e);
+                }
+            }
+        }
+
+        Self { modules: built }
+    }
+
+    /// Dispatch a generic push to the named module, retrying transient
+    /// failures according to that module's configured [`RetryConfig`].
+    pub async fn send(&self, module: &str, token: String, payload: Vec<u8>) -> PushResult<()> {
+        self.send_with(module, token, payload, PushKind::default(), PushMetadata::default())
+            .await
+    }
+
+    /// Dispatch a typed push to the named module, retrying transient
+    /// failures according to that module's configured [`RetryConfig`].
+    pub async fn send_with(
+        &self,
+        module: &str,
+        token: String,
+        payload: Vec<u8>,
+        kind: PushKind,
+        metadata: PushMetadata,
+    ) -> PushResult<()> {
+        let Some(push_module) = self.modules.get(module) else {
+            error!("Unknown push module '{}'", module);
+            return Err(PushError::PushEndpointPersistent);
+        };
+
+        retry_with_backoff(&push_module.retry, || {
+            push_module
+                .backend
+                .send_with(token.clone(), payload.clone(), kind.clone(), metadata.clone())
+        })
+        .await
+    }
+}
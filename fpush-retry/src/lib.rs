@@ -0,0 +1,103 @@
+use fpush_traits::push::{PushError, PushResult};
+use log::{debug, warn};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::time::Duration;
+
+/// Exponential-backoff retry policy applied to a single push backend.
+///
+/// `delay = min(base_delay * 2^attempt, max_delay)`, with an optional random
+/// jitter added on top so that concurrent retries for the same endpoint
+/// don't all land at once.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryConfig {
+    /// Maximum number of attempts, including the first one (1 disables retries)
+    max_attempts: u32,
+    /// Delay before the first retry, in milliseconds
+    base_delay_ms: u64,
+    /// Upper bound on the computed delay, in milliseconds
+    max_delay_ms: u64,
+    /// Add a random jitter (0..=delay) on top of the computed backoff delay
+    jitter: bool,
+}
+
+impl RetryConfig {
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let factor = 1u64.checked_shl(attempt).unwrap_or(u64::MAX);
+        let delay_ms = self.base_delay_ms.saturating_mul(factor).min(self.max_delay_ms);
+        let delay_ms = if self.jitter && delay_ms > 0 {
+            delay_ms.saturating_add(rand::thread_rng().gen_range(0..=delay_ms))
+        } else {
+            delay_ms
+        };
+        Duration::from_millis(delay_ms)
+    }
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay_ms: 200,
+            max_delay_ms: 5_000,
+            jitter: true,
+        }
+    }
+}
+
+/// Request timeout applied when building a backend's `reqwest::Client`.
+///
+/// Kept distinct from [`RetryConfig`] since it bounds a single attempt rather
+/// than the overall retry loop.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeoutConfig {
+    request_timeout_ms: u64,
+}
+
+impl TimeoutConfig {
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms)
+    }
+}
+
+impl Default for TimeoutConfig {
+    fn default() -> Self {
+        Self {
+            request_timeout_ms: 10_000,
+        }
+    }
+}
+
+/// Run `attempt` up to `config.max_attempts()` times, retrying with
+/// exponential backoff on [`PushError::PushEndpointTmp`]. Any other error
+/// (or success) short-circuits immediately.
+pub async fn retry_with_backoff<F, Fut>(config: &RetryConfig, mut attempt: F) -> PushResult<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = PushResult<()>>,
+{
+    let mut attempt_no = 0;
+    loop {
+        match attempt().await {
+            Err(PushError::PushEndpointTmp) if attempt_no + 1 < config.max_attempts() => {
+                let delay = config.delay_for_attempt(attempt_no);
+                warn!(
+                    "Push attempt {} failed with a temporary error, retrying in {:?}",
+                    attempt_no + 1,
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+                attempt_no += 1;
+            }
+            result => {
+                debug!("Push attempt {} result: {:?}", attempt_no + 1, result);
+                return result;
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Errors a push backend can return when attempting to deliver a notification
+#[derive(Debug, Error)]
+pub enum PushError {
+    #[error("failed to load push backend credentials")]
+    CertLoading,
+    #[error("device token is no longer valid")]
+    TokenBlocked,
+    #[error("push endpoint is temporarily unavailable")]
+    PushEndpointTmp,
+    #[error("push endpoint rejected the request")]
+    PushEndpointPersistent,
+    #[error("unknown push endpoint response code: {0}")]
+    Unknown(u16),
+}
+
+pub type PushResult<T> = Result<T, PushError>;
+
+/// The kind of event a notification represents. Lets backends that
+/// distinguish notification types (e.g. Acrobits verbs) pick the right
+/// device-facing wording; backends that don't can ignore it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PushKind {
+    #[default]
+    GenericMessage,
+    IncomingCall,
+    MissedCall,
+    Voicemail,
+}
+
+/// Optional metadata accompanying a typed notification
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PushMetadata {
+    #[serde(default)]
+    pub caller_id: Option<String>,
+    #[serde(default)]
+    pub unread_count: Option<u32>,
+}
+
+#[async_trait]
+pub trait PushTrait {
+    /// Send a push notification to the given device token, carrying an
+    /// opaque payload. Backends whose wire format has no room for arbitrary
+    /// content (e.g. Acrobits' fixed verb/message fields) may ignore it.
+    async fn send(&self, token: String, payload: Vec<u8>) -> PushResult<()>;
+
+    /// Send a typed push notification. Backends that distinguish
+    /// notification kinds should override this; the default implementation
+    /// falls back to [`PushTrait::send`] so existing callers that don't
+    /// specify a kind keep their current behavior.
+    async fn send_with(
+        &self,
+        token: String,
+        payload: Vec<u8>,
+        _kind: PushKind,
+        _metadata: PushMetadata,
+    ) -> PushResult<()> {
+        self.send(token, payload).await
+    }
+}
@@ -0,0 +1,54 @@
+use fpush_retry::{RetryConfig, TimeoutConfig};
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the Windows Notification Service (WNS) push backend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WnsConfig {
+    /// Package Security Identifier (SID) of the registered Windows app
+    package_sid: String,
+    /// Client secret issued alongside the package SID
+    client_secret: String,
+    /// Request timeout and retry/backoff policy for this module
+    #[serde(default)]
+    timeout: TimeoutConfig,
+    #[serde(default)]
+    retry: RetryConfig,
+}
+
+impl WnsConfig {
+    pub fn new(package_sid: String, client_secret: String) -> Self {
+        Self {
+            package_sid,
+            client_secret,
+            timeout: TimeoutConfig::default(),
+            retry: RetryConfig::default(),
+        }
+    }
+
+    pub fn package_sid(&self) -> &str {
+        &self.package_sid
+    }
+
+    pub fn client_secret(&self) -> &str {
+        &self.client_secret
+    }
+
+    pub fn timeout(&self) -> &TimeoutConfig {
+        &self.timeout
+    }
+
+    pub fn retry(&self) -> &RetryConfig {
+        &self.retry
+    }
+}
+
+impl Default for WnsConfig {
+    fn default() -> Self {
+        Self {
+            package_sid: String::new(),
+            client_secret: String::new(),
+            timeout: TimeoutConfig::default(),
+            retry: RetryConfig::default(),
+        }
+    }
+}
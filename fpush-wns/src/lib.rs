@@ -0,0 +1,5 @@
+pub mod config;
+pub mod push;
+
+pub use config::WnsConfig;
+pub use push::FpushWns;
@@ -0,0 +1,147 @@
+use fpush_traits::push::{PushError, PushResult, PushTrait};
+use async_trait::async_trait;
+use log::{debug, error, warn};
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+use crate::config::WnsConfig;
+
+/// Microsoft identity platform token endpoint used to mint WNS access tokens
+const TOKEN_URL: &str = "https://login.microsoftonline.com/common/oauth2/v2.0/token";
+
+pub struct FpushWns {
+    client: reqwest::Client,
+    config: WnsConfig,
+    token: Arc<RwLock<Option<(String, Instant)>>>,
+}
+
+/// Response from the Microsoft identity platform OAuth2 token endpoint
+#[derive(Debug, Deserialize)]
+struct WnsTokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+impl FpushWns {
+    pub fn init(config: &WnsConfig) -> PushResult<Self> {
+        if config.package_sid().is_empty() || config.client_secret().is_empty() {
+            error!("WNS package_sid/client_secret is not configured");
+            return Err(PushError::CertLoading);
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout().request_timeout())
+            .build()
+            .map_err(|e| {
+                error!("Failed to build WNS HTTP client: {}", e);
+                PushError::CertLoading
+            })?;
+
+        Ok(Self {
+            client,
+            config: config.clone(),
+            token: Arc::new(RwLock::new(None)),
+        })
+    }
+
+    /// Return a cached access token if still valid, otherwise mint a new one
+    async fn access_token(&self) -> PushResult<String> {
+        if let Some((token, expires_at)) = self.token.read().await.clone() {
+            if Instant::now() < expires_at {
+                return Ok(token);
+            }
+        }
+        self.refresh_token().await
+    }
+
+    async fn refresh_token(&self) -> PushResult<String> {
+        let params = [
+            ("grant_type", "client_credentials"),
+            ("client_id", self.config.package_sid()),
+            ("client_secret", self.config.client_secret()),
+            ("scope", "notify.windows.com"),
+        ];
+
+        let response = match self.client.post(TOKEN_URL).form(&params).send().await {
+            Ok(response) => response,
+            Err(e) => {
+                error!("Failed to fetch WNS access token: {}", e);
+                return Err(PushError::PushEndpointTmp);
+            }
+        };
+
+        match response.json::<WnsTokenResponse>().await {
+            Ok(token_response) => {
+                let expires_at = Instant::now() + Duration::from_secs(token_response.expires_in);
+                *self.token.write().await = Some((token_response.access_token.clone(), expires_at));
+                Ok(token_response.access_token)
+            }
+            Err(e) => {
+                error!("Failed to parse WNS token response: {}", e);
+                Err(PushError::PushEndpointTmp)
+            }
+        }
+    }
+
+    async fn drop_token(&self) {
+        *self.token.write().await = None;
+    }
+
+    async fn send_raw(
+        &self,
+        channel_url: &str,
+        access_token: &str,
+        payload: Vec<u8>,
+        allow_retry: bool,
+    ) -> PushResult<()> {
+        debug!("Sending WNS push to channel: {}", channel_url);
+
+        let response = match self
+            .client
+            .post(channel_url)
+            .header("X-WNS-Type", "wns/raw")
+            .header("Content-Type", "application/octet-stream")
+            .header("Authorization", format!("Bearer {}", access_token))
+            .body(payload.clone())
+            .send()
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                error!("Failed to send request to WNS: {}", e);
+                return Err(PushError::PushEndpointTmp);
+            }
+        };
+
+        match response.status().as_u16() {
+            200 => Ok(()),
+            // 410 Gone means the channel URI has expired and will never be valid again
+            410 => Err(PushError::TokenBlocked),
+            // 401 means the access token was rejected; refresh it and retry once
+            401 if allow_retry => {
+                warn!("WNS access token rejected, refreshing and retrying");
+                self.drop_token().await;
+                let access_token = self.refresh_token().await?;
+                Box::pin(self.send_raw(channel_url, &access_token, payload, false)).await
+            }
+            401 => Err(PushError::PushEndpointPersistent),
+            // 406/429 indicate the device or app is throttled, retry later
+            406 | 429 => Err(PushError::PushEndpointTmp),
+            // 400 is a bad request (malformed channel URI or headers)
+            400 => Err(PushError::PushEndpointPersistent),
+            code => {
+                error!("Received unhandled status code from WNS: {}", code);
+                Err(PushError::Unknown(code))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl PushTrait for FpushWns {
+    async fn send(&self, token: String, payload: Vec<u8>) -> PushResult<()> {
+        let access_token = self.access_token().await?;
+        self.send_raw(&token, &access_token, payload, true).await
+    }
+}
@@ -0,0 +1,105 @@
+// gRPC service surface for fpush
+//
+// Exposes a typed PushService next to the actix HTTP server for internal
+// callers that want a strongly-contracted integration instead of the demo
+// JSON/WebSocket endpoints. Generated from proto/push.proto.
+
+use fpush_push::FpushPush;
+use fpush_traits::push::PushError;
+use std::sync::Arc;
+use tonic::{Request, Response, Status};
+
+pub mod proto {
+    tonic::include_proto!("push");
+}
+
+use proto::push_service_server::{PushService, PushServiceServer};
+use proto::{PushStatus, SendPushBatchRequest, SendPushRequest, StatusCode};
+
+pub struct FpushGrpc {
+    push: Arc<FpushPush>,
+}
+
+impl FpushGrpc {
+    pub fn new(push: Arc<FpushPush>) -> Self {
+        Self { push }
+    }
+
+    pub fn into_service(self) -> PushServiceServer<Self> {
+        PushServiceServer::new(self)
+    }
+}
+
+fn status_for_error(e: PushError) -> Status {
+    match e {
+        PushError::TokenBlocked => Status::not_found("device token is no longer registered"),
+        PushError::PushEndpointTmp => Status::unavailable("push endpoint temporarily unavailable"),
+        PushError::PushEndpointPersistent => Status::invalid_argument("push endpoint rejected the request"),
+        PushError::CertLoading | PushError::Unknown(_) => Status::internal("unexpected push error"),
+    }
+}
+
+#[tonic::async_trait]
+impl PushService for FpushGrpc {
+    async fn send_push(&self, request: Request<SendPushRequest>) -> Result<Response<PushStatus>, Status> {
+        let req = request.into_inner();
+
+        match self.push.send(&req.module, req.device_token.clone(), req.payload).await {
+            Ok(()) => Ok(Response::new(PushStatus {
+                device_token: req.device_token,
+                status: StatusCode::Delivered as i32,
+            })),
+            Err(e) => Err(status_for_error(e)),
+        }
+    }
+
+    type SendPushBatchStream = tokio_stream::wrappers::ReceiverStream<Result<PushStatus, Status>>;
+
+    async fn send_push_batch(
+        &self,
+        request: Request<SendPushBatchRequest>,
+    ) -> Result<Response<Self::SendPushBatchStream>, Status> {
+        let req = request.into_inner();
+        let (tx, rx) = tokio::sync::mpsc::channel(16);
+        let push = self.push.clone();
+        let module = req.module;
+        let payload = req.payload;
+
+        tokio::spawn(async move {
+            for device_token in req.device_tokens {
+                let status = match push.send(&module, device_token.clone(), payload.clone()).await {
+                    Ok(()) => StatusCode::Delivered,
+                    Err(PushError::TokenBlocked) => StatusCode::TokenBlocked,
+                    Err(PushError::PushEndpointTmp) => StatusCode::TemporaryFailure,
+                    Err(_) => StatusCode::PersistentFailure,
+                };
+
+                let sent = tx
+                    .send(Ok(PushStatus {
+                        device_token,
+                        status: status as i32,
+                    }))
+                    .await;
+                if sent.is_err() {
+                    // receiver dropped (client disconnected), stop the batch early
+                    break;
+                }
+            }
+        });
+
+        Ok(Response::new(tokio_stream::wrappers::ReceiverStream::new(rx)))
+    }
+}
+
+/// Start the gRPC server on the specified bind address
+pub async fn start_grpc_server(bind_addr: String, push: Arc<FpushPush>) -> Result<(), tonic::transport::Error> {
+    log::info!("Starting gRPC server on grpc://{}", bind_addr);
+
+    let addr = bind_addr.parse().expect("invalid GRPC_BIND address");
+    let service = FpushGrpc::new(push).into_service();
+
+    tonic::transport::Server::builder()
+        .add_service(service)
+        .serve(addr)
+        .await
+}
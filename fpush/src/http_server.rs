@@ -1,5 +1,7 @@
 // HTTP server module for fpush
-// Provides a REST API with POST /fetch_messages endpoint for demo/testing purposes
+// Provides a REST API with POST /fetch_messages endpoint for demo/testing purposes,
+// and a /ws/push WebSocket endpoint that lets backend services push notifications
+// without going through the XMPP component connection.
 //
 // ## Configuration
 //
@@ -40,10 +42,16 @@
 //   -d '{"username":"user","password":"pass","last_id":"","last_sent_id":"","device":"device1"}'
 // ```
 
-use actix_web::{middleware, web, App, HttpRequest, HttpResponse, HttpServer};
+use actix::{Actor, ActorContext, AsyncContext, Handler, Message, StreamHandler};
+use actix_web::{middleware, web, App, Error, HttpRequest, HttpResponse, HttpServer};
+use actix_web_actors::ws;
 use chrono::{DateTime, Utc};
+use fpush_push::FpushPush;
+use fpush_traits::push::{PushError, PushKind, PushMetadata};
 use serde::{Deserialize, Serialize};
 use std::env;
+use std::sync::Arc;
+use subtle::ConstantTimeEq;
 
 #[derive(Debug, Deserialize)]
 struct FetchMessagesRequest {
@@ -201,16 +209,234 @@ async fn fetch_messages(
         .json(response)
 }
 
+/// JSON init frame a WebSocket client must send as its first message
+#[derive(Debug, Deserialize)]
+struct InitFrame {
+    device_id: String,
+    access_token: String,
+    module: String,
+}
+
+/// JSON frame requesting delivery of a single push, sent after the init frame
+#[derive(Debug, Deserialize)]
+struct PushFrame {
+    request_id: String,
+    token: String,
+    /// Notification kind (defaults to a generic message if omitted), so
+    /// callers that know the event type (incoming call, voicemail, ...) can
+    /// get the right device-facing wording instead of a generic message.
+    #[serde(default)]
+    kind: PushKind,
+    #[serde(default)]
+    metadata: PushMetadata,
+    /// Opaque payload forwarded to the backend. A JSON string is sent as-is
+    /// (UTF-8 bytes), an absent or null payload is sent as empty bytes, and
+    /// any other JSON value is forwarded as its serialized form.
+    #[serde(default)]
+    payload: serde_json::Value,
+}
+
+/// Delivery outcome reported back for a single `PushFrame`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum DeliveryStatus {
+    Delivered,
+    TokenBlocked,
+    TemporaryFailure,
+    PersistentFailure,
+}
+
+impl From<&PushError> for DeliveryStatus {
+    fn from(e: &PushError) -> Self {
+        match e {
+            PushError::TokenBlocked => DeliveryStatus::TokenBlocked,
+            PushError::PushEndpointTmp => DeliveryStatus::TemporaryFailure,
+            PushError::PushEndpointPersistent | PushError::CertLoading | PushError::Unknown(_) => {
+                DeliveryStatus::PersistentFailure
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct StatusFrame {
+    request_id: String,
+    status: DeliveryStatus,
+}
+
+/// Actor message used to hand a completed delivery status back to the socket
+/// from the spawned task that awaited the push dispatch.
+#[derive(Message)]
+#[rtype(result = "()")]
+struct SendStatus(StatusFrame);
+
+/// Per-connection WebSocket actor for the push dispatch gateway
+struct PushSocket {
+    push: Arc<FpushPush>,
+    /// Shared secret the init frame's `access_token` must match; `None` means
+    /// the gateway has no secret configured and every connection is refused
+    auth_token: Arc<Option<String>>,
+    module: Option<String>,
+}
+
+impl Actor for PushSocket {
+    type Context = ws::WebsocketContext<Self>;
+}
+
+impl Handler<SendStatus> for PushSocket {
+    type Result = ();
+
+    fn handle(&mut self, msg: SendStatus, ctx: &mut Self::Context) {
+        match serde_json::to_string(&msg.0) {
+            Ok(text) => ctx.text(text),
+            Err(e) => log::error!("Failed to serialize push status frame: {}", e),
+        }
+    }
+}
+
+impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for PushSocket {
+    fn handle(&mut self, msg: Result<ws::Message, ws::ProtocolError>, ctx: &mut Self::Context) {
+        let msg = match msg {
+            Ok(msg) => msg,
+            Err(e) => {
+                log::warn!("Push WebSocket protocol error: {}", e);
+                ctx.stop();
+                return;
+            }
+        };
+
+        match msg {
+            ws::Message::Ping(bytes) => ctx.pong(&bytes),
+            ws::Message::Text(text) => self.handle_frame(&text, ctx),
+            ws::Message::Close(reason) => {
+                ctx.close(reason);
+                ctx.stop();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Compare the presented access_token against the configured shared secret
+/// in constant time, to avoid a timing side-channel on WS_PUSH_TOKEN.
+fn access_token_matches(presented: &str, configured: Option<&String>) -> bool {
+    match configured {
+        Some(configured) => presented.as_bytes().ct_eq(configured.as_bytes()).into(),
+        None => false,
+    }
+}
+
+impl PushSocket {
+    fn handle_frame(&mut self, text: &str, ctx: &mut ws::WebsocketContext<Self>) {
+        let Some(module) = self.module.clone() else {
+            match serde_json::from_str::<InitFrame>(text) {
+                Ok(init) if init.device_id.is_empty() || init.access_token.is_empty() => {
+                    log::warn!("Push WebSocket init frame missing device_id/access_token");
+                    ctx.text(serde_json::json!({"error": "device_id and access_token are required"}).to_string());
+                    ctx.stop();
+                }
+                Ok(init) if !access_token_matches(&init.access_token, self.auth_token.as_ref().as_ref()) => {
+                    log::warn!(
+                        "Push WebSocket device {} presented an invalid access_token",
+                        init.device_id
+                    );
+                    ctx.text(serde_json::json!({"error": "invalid access_token"}).to_string());
+                    ctx.stop();
+                }
+                Ok(init) => {
+                    log::info!(
+                        "Push WebSocket device {} attached to module {}",
+                        init.device_id,
+                        init.module
+                    );
+                    self.module = Some(init.module);
+                }
+                Err(e) => {
+                    log::warn!("Invalid push WebSocket init frame: {}", e);
+                    ctx.text(serde_json::json!({"error": "expected init frame"}).to_string());
+                    ctx.stop();
+                }
+            }
+            return;
+        };
+
+        let frame: PushFrame = match serde_json::from_str(text) {
+            Ok(frame) => frame,
+            Err(e) => {
+                log::warn!("Invalid push WebSocket request frame: {}", e);
+                ctx.text(serde_json::json!({"error": "invalid push frame"}).to_string());
+                return;
+            }
+        };
+
+        let push = self.push.clone();
+        let addr = ctx.address();
+        let request_id = frame.request_id.clone();
+
+        actix::spawn(async move {
+            let payload = match frame.payload {
+                serde_json::Value::Null => Vec::new(),
+                serde_json::Value::String(s) => s.into_bytes(),
+                other => serde_json::to_vec(&other).unwrap_or_default(),
+            };
+
+            let status = match push
+                .send_with(&module, frame.token, payload, frame.kind, frame.metadata)
+                .await
+            {
+                Ok(()) => DeliveryStatus::Delivered,
+                Err(e) => DeliveryStatus::from(&e),
+            };
+
+            addr.do_send(SendStatus(StatusFrame { request_id, status }));
+        });
+    }
+}
+
+/// Upgrade a connection to the `/ws/push` WebSocket dispatch gateway
+async fn push_ws(
+    req: HttpRequest,
+    stream: web::Payload,
+    push: web::Data<Arc<FpushPush>>,
+    auth_token: web::Data<Arc<Option<String>>>,
+) -> Result<HttpResponse, Error> {
+    ws::start(
+        PushSocket {
+            push: push.get_ref().clone(),
+            auth_token: auth_token.get_ref().clone(),
+            module: None,
+        },
+        &req,
+        stream,
+    )
+}
+
 /// Start HTTP server on the specified bind address
-/// This server provides a demo /fetch_messages endpoint for testing
-pub async fn start_http_server(bind_addr: String) -> std::io::Result<()> {
+/// This server provides a demo /fetch_messages endpoint for testing and the
+/// /ws/push real-time push dispatch gateway
+///
+/// The WebSocket gateway requires a shared secret configured via the
+/// WS_PUSH_TOKEN environment variable; every init frame's `access_token`
+/// must match it, or the connection is refused. If WS_PUSH_TOKEN is unset,
+/// the gateway refuses all connections rather than accepting unauthenticated
+/// clients.
+pub async fn start_http_server(bind_addr: String, push: Arc<FpushPush>) -> std::io::Result<()> {
     log::info!("Starting HTTP server on http://{}", bind_addr);
     log::info!("POST /fetch_messages endpoint is ready");
+    log::info!("WS /ws/push endpoint is ready");
+
+    let auth_token: Arc<Option<String>> = Arc::new(env::var("WS_PUSH_TOKEN").ok());
+    if auth_token.is_none() {
+        log::warn!("WS_PUSH_TOKEN is not set; /ws/push will refuse all connections");
+    }
 
-    HttpServer::new(|| {
+    HttpServer::new(move || {
         App::new()
             .wrap(middleware::Logger::default())
+            .app_data(web::Data::new(push.clone()))
+            .app_data(web::Data::new(auth_token.clone()))
             .route("/fetch_messages", web::post().to(fetch_messages))
+            .route("/ws/push", web::get().to(push_ws))
     })
     .bind(&bind_addr)?
     .run()
@@ -1,6 +1,8 @@
+mod amqp;
 mod config;
 mod error;
 mod xmpp;
+mod grpc;
 mod http_server;
 
 use fpush_push::FpushPush;
@@ -42,12 +44,30 @@ async fn main() {
 
     // Start HTTP server in a separate task
     let http_bind_addr = std::env::var("HTTP_BIND").unwrap_or_else(|_| "127.0.0.1:8080".to_string());
+    let http_push_impl = push_impl.clone();
     tokio::spawn(async move {
-        if let Err(e) = http_server::start_http_server(http_bind_addr).await {
+        if let Err(e) = http_server::start_http_server(http_bind_addr, http_push_impl).await {
             error!("HTTP server error: {}", e);
         }
     });
 
+    // Optionally consume push jobs from an AMQP/RabbitMQ queue
+    if let Some(amqp_config) = settings.amqp().cloned() {
+        let amqp_push_impl = push_impl.clone();
+        tokio::spawn(async move {
+            amqp::run_consumer_with_reconnect(amqp_config, amqp_push_impl).await;
+        });
+    }
+
+    // Start the gRPC server in a separate task
+    let grpc_bind_addr = std::env::var("GRPC_BIND").unwrap_or_else(|_| "127.0.0.1:50051".to_string());
+    let grpc_push_impl = push_impl.clone();
+    tokio::spawn(async move {
+        if let Err(e) = grpc::start_grpc_server(grpc_bind_addr, grpc_push_impl).await {
+            error!("gRPC server error: {}", e);
+        }
+    });
+
     // Main XMPP connection loop
     loop {
         info!(
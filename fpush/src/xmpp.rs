@@ -0,0 +1,108 @@
+// XMPP Component (XEP-0114) ingestion for fpush
+//
+// Connects to the XMPP server as an external component and translates
+// incoming stanzas into push dispatches through the shared `FpushPush`.
+// This is the original, always-on ingestion path; the WebSocket, AMQP, and
+// gRPC surfaces in this crate are later additions for callers that don't
+// want to run an XMPP component.
+
+use crate::config::Settings;
+use fpush_push::FpushPush;
+use fpush_traits::push::{PushKind, PushMetadata};
+use futures::StreamExt;
+use log::{debug, error, warn};
+use std::sync::Arc;
+use tokio_xmpp::Component;
+use xmpp_parsers::Element;
+
+/// XML namespace for the push notification stanza the XMPP server sends us
+const PUSH_NS: &str = "urn:xmpp:fpush:0";
+
+/// Open a new component connection using the configured server/credentials
+pub async fn init_component_connection(settings: &Settings) -> Result<Component, tokio_xmpp::Error> {
+    let component = settings.component();
+    Component::new(
+        component.jid(),
+        component.secret(),
+        component.server_hostname(),
+        component.server_port(),
+    )
+    .await
+}
+
+/// Drain stanzas from the component until the connection drops, dispatching
+/// each recognized push stanza through `push`. Returns once the stream ends;
+/// `main`'s reconnect loop is responsible for opening a new connection.
+pub async fn message_loop_main_thread(mut component: Component, push: Arc<FpushPush>) {
+    while let Some(stanza) = component.next().await {
+        let Some(event) = parse_push_stanza(&stanza) else {
+            continue;
+        };
+
+        let push = push.clone();
+        tokio::spawn(async move {
+            debug!(
+                "Dispatching XMPP push event for module '{}', kind {:?}",
+                event.module, event.kind
+            );
+            if let Err(e) = push
+                .send_with(&event.module, event.token, event.payload, event.kind, event.metadata)
+                .await
+            {
+                error!("Failed to dispatch XMPP push event: {:?}", e);
+            }
+        });
+    }
+
+    warn!("XMPP component stream closed");
+}
+
+/// A push request extracted from an incoming `<push/>` stanza
+struct PushEvent {
+    module: String,
+    token: String,
+    payload: Vec<u8>,
+    kind: PushKind,
+    metadata: PushMetadata,
+}
+
+/// Parse a `<push xmlns='urn:xmpp:fpush:0' module='...' token='...' type='...'>`
+/// stanza, as sent by the XMPP server when a voicemail, missed-call, or
+/// incoming-call event needs to wake a device. `type` maps to [`PushKind`]
+/// (defaulting to a generic message for anything else) so these events reach
+/// the device with the same typed wording as the WebSocket surface, instead
+/// of a generic "New Message". Any other stanza is ignored.
+fn parse_push_stanza(element: &Element) -> Option<PushEvent> {
+    if element.name() != "push" || element.ns() != PUSH_NS {
+        return None;
+    }
+
+    let module = element.attr("module")?.to_string();
+    let token = element.attr("token")?.to_string();
+    let kind = match element.attr("type") {
+        Some("incoming_call") => PushKind::IncomingCall,
+        Some("missed_call") => PushKind::MissedCall,
+        Some("voicemail") => PushKind::Voicemail,
+        _ => PushKind::GenericMessage,
+    };
+
+    let metadata = PushMetadata {
+        caller_id: element.get_child("caller-id", PUSH_NS).map(|c| c.text()),
+        unread_count: element
+            .get_child("unread-count", PUSH_NS)
+            .and_then(|c| c.text().parse().ok()),
+    };
+
+    let payload = element
+        .get_child("payload", PUSH_NS)
+        .map(|c| c.text().into_bytes())
+        .unwrap_or_default();
+
+    Some(PushEvent {
+        module,
+        token,
+        payload,
+        kind,
+        metadata,
+    })
+}